@@ -1,39 +1,389 @@
-use std::{ops::{self, Range}, fmt::Display};
+use std::{f64::consts::PI, ops::{self, Range}, fmt::Display};
 
 use crate::random::{random_f64, random_f64_in_range};
 
-#[derive(Copy, Clone, Debug)]
-pub struct Vector3 {
+// Vector3's storage is split into a SIMD-backed representation on targets
+// that support it and a scalar fallback everywhere else. Both live behind
+// the same private `Lanes` API so every operator impl below is written once
+// against `Lanes` rather than duplicated per backend.
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx"))]
+mod lanes {
+    use core::arch::x86_64::*;
+
+    /// 256-bit AVX lane holding `(x, y, z, <pad>)`.
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct Lanes(__m256d);
+
+    impl Lanes {
+        #[inline]
+        pub const fn new(x: f64, y: f64, z: f64) -> Self {
+            // `__m256d` is bit-for-bit a `[f64; 4]` in (x, y, z, <pad>) lane
+            // order, so a const transmute builds it without going through
+            // the (non-const) `_mm256_set_pd` intrinsic.
+            unsafe { Self(core::mem::transmute::<[f64; 4], __m256d>([x, y, z, 0.0])) }
+        }
+
+        #[inline]
+        pub fn splat(v: f64) -> Self {
+            unsafe { Self(_mm256_set1_pd(v)) }
+        }
+
+        #[inline]
+        fn to_array(self) -> [f64; 4] {
+            let mut out = [0.0_f64; 4];
+            unsafe { _mm256_storeu_pd(out.as_mut_ptr(), self.0) };
+            out
+        }
+
+        // Real single-lane extracts rather than a `to_array()` round-trip
+        // through memory — these are called per-component all over the
+        // callers in this module (`coordinate_system`, `local_to_world`, ...), so
+        // a full store per component would be slower than the scalar field
+        // read they replaced.
+        #[inline]
+        pub fn x(self) -> f64 {
+            unsafe { _mm256_cvtsd_f64(self.0) }
+        }
+        #[inline]
+        pub fn y(self) -> f64 {
+            // Select lane 1 into lane 0 of the low 128 bits, then extract.
+            unsafe { _mm256_cvtsd_f64(_mm256_permute_pd(self.0, 0b0001)) }
+        }
+        #[inline]
+        pub fn z(self) -> f64 {
+            // Lane 2 lives in the low element of the upper 128-bit half.
+            unsafe { _mm_cvtsd_f64(_mm256_extractf128_pd(self.0, 1)) }
+        }
+
+        #[inline]
+        pub fn add(self, rhs: Self) -> Self {
+            unsafe { Self(_mm256_add_pd(self.0, rhs.0)) }
+        }
+
+        #[inline]
+        pub fn sub(self, rhs: Self) -> Self {
+            unsafe { Self(_mm256_sub_pd(self.0, rhs.0)) }
+        }
+
+        #[inline]
+        pub fn mul(self, rhs: Self) -> Self {
+            unsafe { Self(_mm256_mul_pd(self.0, rhs.0)) }
+        }
+
+        #[inline]
+        pub fn neg(self) -> Self {
+            unsafe { Self(_mm256_sub_pd(_mm256_setzero_pd(), self.0)) }
+        }
+
+        /// Sum of the x/y/z lanes (the padding lane is not read, so it
+        /// never contributes).
+        #[inline]
+        pub fn horizontal_sum(self) -> f64 {
+            let a = self.to_array();
+            a[0] + a[1] + a[2]
+        }
+
+        #[inline]
+        pub fn dot(self, rhs: Self) -> f64 {
+            self.mul(rhs).horizontal_sum()
+        }
+
+        // Permutes the lanes `(x, y, z, pad)` to `(y, z, x, pad)`. The two
+        // 64-bit lanes making up the cross product's shuffles straddle the
+        // 128-bit halves, so this goes through the low/high halves rather
+        // than a single in-lane `_mm256_permute_pd`.
+        #[inline]
+        unsafe fn permute_yzx(v: __m256d) -> __m256d {
+            unsafe {
+                let lo = _mm256_castpd256_pd128(v);
+                let hi = _mm256_extractf128_pd(v, 1);
+                let new_lo = _mm_shuffle_pd::<0b01>(lo, hi);
+                let new_hi = _mm_shuffle_pd::<0b10>(lo, hi);
+                _mm256_insertf128_pd::<1>(_mm256_castpd128_pd256(new_lo), new_hi)
+            }
+        }
+
+        /// Permutes the lanes `(x, y, z, pad)` to `(z, x, y, pad)`.
+        #[inline]
+        unsafe fn permute_zxy(v: __m256d) -> __m256d {
+            unsafe {
+                let lo = _mm256_castpd256_pd128(v);
+                let hi = _mm256_extractf128_pd(v, 1);
+                let new_lo = _mm_shuffle_pd::<0b00>(hi, lo);
+                let new_hi = _mm_shuffle_pd::<0b11>(lo, hi);
+                _mm256_insertf128_pd::<1>(_mm256_castpd128_pd256(new_lo), new_hi)
+            }
+        }
+
+        /// `cross(a, b) = yzx(a)*zxy(b) - zxy(a)*yzx(b)`, computed entirely
+        /// over shuffled/multiplied/subtracted vectors with no per-component
+        /// extracts in between.
+        #[inline]
+        pub fn cross(self, rhs: Self) -> Self {
+            unsafe {
+                let a_yzx = Self(Self::permute_yzx(self.0));
+                let a_zxy = Self(Self::permute_zxy(self.0));
+                let b_yzx = Self(Self::permute_yzx(rhs.0));
+                let b_zxy = Self(Self::permute_zxy(rhs.0));
+                a_yzx.mul(b_zxy).sub(a_zxy.mul(b_yzx))
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod lanes {
+    use core::arch::wasm32::*;
+
+    /// Two 128-bit wasm SIMD lanes: `xy` holds `(x, y)`, `zw` holds `(z, <pad>)`.
+    /// `repr(C)` pins the field order so the pair is addressable as a
+    /// contiguous `[f64; 4]` for `Vector3`'s `Index`/`IndexMut` impls.
+    #[derive(Copy, Clone)]
+    #[repr(C)]
+    pub struct Lanes {
+        xy: v128,
+        zw: v128,
+    }
+
+    impl Lanes {
+        #[inline]
+        pub const fn new(x: f64, y: f64, z: f64) -> Self {
+            unsafe {
+                Self {
+                    xy: core::mem::transmute::<[f64; 2], v128>([x, y]),
+                    zw: core::mem::transmute::<[f64; 2], v128>([z, 0.0]),
+                }
+            }
+        }
+
+        #[inline]
+        pub fn splat(v: f64) -> Self {
+            Self { xy: f64x2_splat(v), zw: f64x2(v, 0.0) }
+        }
+
+        #[inline]
+        pub fn x(self) -> f64 { f64x2_extract_lane::<0>(self.xy) }
+        #[inline]
+        pub fn y(self) -> f64 { f64x2_extract_lane::<1>(self.xy) }
+        #[inline]
+        pub fn z(self) -> f64 { f64x2_extract_lane::<0>(self.zw) }
+
+        #[inline]
+        pub fn add(self, rhs: Self) -> Self {
+            Self { xy: f64x2_add(self.xy, rhs.xy), zw: f64x2_add(self.zw, rhs.zw) }
+        }
+
+        #[inline]
+        pub fn sub(self, rhs: Self) -> Self {
+            Self { xy: f64x2_sub(self.xy, rhs.xy), zw: f64x2_sub(self.zw, rhs.zw) }
+        }
+
+        #[inline]
+        pub fn mul(self, rhs: Self) -> Self {
+            Self { xy: f64x2_mul(self.xy, rhs.xy), zw: f64x2_mul(self.zw, rhs.zw) }
+        }
+
+        #[inline]
+        pub fn neg(self) -> Self {
+            Self { xy: f64x2_neg(self.xy), zw: f64x2_neg(self.zw) }
+        }
+
+        #[inline]
+        pub fn dot(self, rhs: Self) -> f64 {
+            let p = self.mul(rhs);
+            p.x() + p.y() + p.z()
+        }
+
+        #[inline]
+        pub fn cross(self, rhs: Self) -> Self {
+            Self::new(
+                self.y() * rhs.z() - self.z() * rhs.y(),
+                self.z() * rhs.x() - self.x() * rhs.z(),
+                self.x() * rhs.y() - self.y() * rhs.x(),
+            )
+        }
+    }
+}
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx"),
+    all(target_arch = "wasm32", target_feature = "simd128"),
+)))]
+mod lanes {
+    /// Plain scalar fallback, kept field-for-field identical to the
+    /// struct layout this crate has always used. `repr(C)` pins the field
+    /// order so `Vector3`'s `Index`/`IndexMut` impls can address it as a
+    /// contiguous `[f64; 3]`.
+    #[derive(Copy, Clone)]
+    #[repr(C)]
+    pub struct Lanes {
+        x: f64,
+        y: f64,
+        z: f64,
+    }
+
+    impl Lanes {
+        #[inline]
+        pub const fn new(x: f64, y: f64, z: f64) -> Self {
+            Self { x, y, z }
+        }
+
+        #[inline]
+        pub fn splat(v: f64) -> Self {
+            Self { x: v, y: v, z: v }
+        }
+
+        #[inline]
+        pub fn x(self) -> f64 { self.x }
+        #[inline]
+        pub fn y(self) -> f64 { self.y }
+        #[inline]
+        pub fn z(self) -> f64 { self.z }
+
+        #[inline]
+        pub fn add(self, rhs: Self) -> Self {
+            Self { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+        }
+
+        #[inline]
+        pub fn sub(self, rhs: Self) -> Self {
+            Self { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+        }
+
+        #[inline]
+        pub fn mul(self, rhs: Self) -> Self {
+            Self { x: self.x * rhs.x, y: self.y * rhs.y, z: self.z * rhs.z }
+        }
+
+        #[inline]
+        pub fn neg(self) -> Self {
+            Self { x: -self.x, y: -self.y, z: -self.z }
+        }
+
+        #[inline]
+        pub fn dot(self, rhs: Self) -> f64 {
+            self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+        }
+
+        #[inline]
+        pub fn cross(self, rhs: Self) -> Self {
+            Self {
+                x: self.y * rhs.z - self.z * rhs.y,
+                y: self.z * rhs.x - self.x * rhs.z,
+                z: self.x * rhs.y - self.y * rhs.x,
+            }
+        }
+    }
+}
+
+// Safety invariant relied on below: whichever `lanes::Lanes` backend is
+// active, its first three 8-byte lanes hold x, y, z in that order with no
+// gap before them (the x86_64 AVX `Lanes` is bit-for-bit a `[f64; 4]`, and
+// the wasm32/scalar `Lanes` are `#[repr(C)]` with x/y/z first — see each
+// module's doc comment above). That lets `Vector3`, which is
+// `#[repr(transparent)]` over `Lanes`, be reinterpreted as a leading
+// `[f64; 3]`/`Vector3Parts` without going through the (method-based)
+// `Lanes` API, which is what `Deref`/`DerefMut` and the raw-pointer
+// `Index`/`IndexMut` impls below both do. Don't change one backend's
+// layout without checking every use of this invariant.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct Vector3(lanes::Lanes);
+
+impl PartialEq for Vector3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x() == other.x() && self.y() == other.y() && self.z() == other.z()
+    }
+}
+
+impl ops::Index<usize> for Vector3 {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &f64 {
+        assert!(index < 3, "Vector3 index out of bounds: {index}");
+        unsafe { &*(self as *const Vector3 as *const f64).add(index) }
+    }
+}
+
+impl ops::IndexMut<usize> for Vector3 {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        assert!(index < 3, "Vector3 index out of bounds: {index}");
+        unsafe { &mut *(self as *mut Vector3 as *mut f64).add(index) }
+    }
+}
+
+/// An unpacked, component-wise view of a [`Vector3`].
+///
+/// The packed/SIMD representations can't store `.x`/`.y`/`.z` as ordinary
+/// struct fields, so `Vector3` derefs to this named-field form — existing
+/// call sites that read or write `some_vector.x` keep compiling unchanged,
+/// same as before this type grew a packed backend.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Vector3Parts {
     pub x: f64,
     pub y: f64,
     pub z: f64
 }
 
+impl From<Vector3> for Vector3Parts {
+    fn from(v: Vector3) -> Self {
+        Self { x: v.x(), y: v.y(), z: v.z() }
+    }
+}
+
+impl From<Vector3Parts> for Vector3 {
+    fn from(p: Vector3Parts) -> Self {
+        Self::from(p.x, p.y, p.z)
+    }
+}
+
+impl ops::Deref for Vector3 {
+    type Target = Vector3Parts;
+
+    fn deref(&self) -> &Vector3Parts {
+        // Sound by the layout invariant documented on `Vector3` above;
+        // `Vector3Parts` is `#[repr(C)]` with x/y/z in that same order, so
+        // it's a valid view over the same bytes.
+        unsafe { &*(self as *const Vector3 as *const Vector3Parts) }
+    }
+}
+
+impl ops::DerefMut for Vector3 {
+    fn deref_mut(&mut self) -> &mut Vector3Parts {
+        unsafe { &mut *(self as *mut Vector3 as *mut Vector3Parts) }
+    }
+}
+
+impl std::fmt::Debug for Vector3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Vector3")
+            .field("x", &self.x())
+            .field("y", &self.y())
+            .field("z", &self.z())
+            .finish()
+    }
+}
+
 impl ops::Neg for Vector3 {
     type Output = Vector3;
 
     fn neg(self) -> Self::Output {
-        Self {
-            x: -self.x,
-            y: -self.y,
-            z: -self.z
-        }
+        Self(self.0.neg())
     }
 }
 
 impl ops::AddAssign for Vector3 {
     fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
+        self.0 = self.0.add(rhs.0);
     }
 }
 
 impl ops::MulAssign<f64> for Vector3 {
     fn mul_assign(&mut self, rhs: f64) {
-        self.x *= rhs;
-        self.y *= rhs;
-        self.z *= rhs;
+        self.0 = self.0.mul(lanes::Lanes::splat(rhs));
     }
 }
 
@@ -45,84 +395,56 @@ impl ops::DivAssign<f64> for Vector3 {
 
 impl Display for Vector3 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {} {}", self.x, self.y, self.z)
+        write!(f, "{} {} {}", self.x(), self.y(), self.z())
     }
 }
 
 impl ops::Add for Vector3 {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z
-        }
+        Self(self.0.add(rhs.0))
     }
 }
 
 impl ops::Sub for Vector3 {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-            z: self.z - rhs.z
-        }
+        Self(self.0.sub(rhs.0))
     }
 }
 
 impl ops::Mul for Vector3 {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x * rhs.x,
-            y: self.y * rhs.y,
-            z: self.z * rhs.z
-        }
+        Self(self.0.mul(rhs.0))
     }
 }
 
 impl ops::Mul<f64> for Vector3 {
     type Output = Self;
     fn mul(self, rhs: f64) -> Self::Output {
-        Self {
-            x: self.x * rhs,
-            y: self.y * rhs,
-            z: self.z * rhs
-        }
+        Self(self.0.mul(lanes::Lanes::splat(rhs)))
     }
 }
 
 impl ops::Mul<Vector3> for f64 {
     type Output = Vector3;
     fn mul(self, rhs: Vector3) -> Self::Output {
-        Self::Output {
-            x: self * rhs.x,
-            y: self * rhs.y,
-            z: self * rhs.z,
-        }
+        rhs * self
     }
 }
 
 impl ops::Mul<Vector3> for usize {
     type Output = Vector3;
     fn mul(self, rhs: Vector3) -> Self::Output {
-        Self::Output {
-            x: self as f64 * rhs.x,
-            y: self as f64 * rhs.y,
-            z: self as f64 * rhs.z,
-        }
+        rhs * (self as f64)
     }
 }
 
 impl ops::Mul<Vector3> for i64 {
     type Output = Vector3;
     fn mul(self, rhs: Vector3) -> Self::Output {
-        Self::Output {
-            x: self as f64 * rhs.x,
-            y: self as f64 * rhs.y,
-            z: self as f64 * rhs.z,
-        }
+        rhs * (self as f64)
     }
 }
 
@@ -134,104 +456,171 @@ impl ops::Div<f64> for Vector3 {
 }
 
 impl Vector3 {
+    /// The zero vector, `(0, 0, 0)`
+    pub const ZERO: Self = Self::from(0.0, 0.0, 0.0);
+    /// `(1, 1, 1)`
+    pub const ONE: Self = Self::from(1.0, 1.0, 1.0);
+    /// `(-1, -1, -1)`
+    pub const NEG_ONE: Self = Self::from(-1.0, -1.0, -1.0);
+    /// The positive X axis, `(1, 0, 0)`
+    pub const X: Self = Self::from(1.0, 0.0, 0.0);
+    /// The positive Y axis, `(0, 1, 0)`
+    pub const Y: Self = Self::from(0.0, 1.0, 0.0);
+    /// The positive Z axis, `(0, 0, 1)`
+    pub const Z: Self = Self::from(0.0, 0.0, 1.0);
+    /// The negative X axis, `(-1, 0, 0)`
+    pub const NEG_X: Self = Self::from(-1.0, 0.0, 0.0);
+    /// The negative Y axis, `(0, -1, 0)`
+    pub const NEG_Y: Self = Self::from(0.0, -1.0, 0.0);
+    /// The negative Z axis, `(0, 0, -1)`
+    pub const NEG_Z: Self = Self::from(0.0, 0.0, -1.0);
+
     /// Creates an empty Vector
     pub const fn new() -> Self {
-        Self {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0
-        }
+        Self(lanes::Lanes::new(0.0, 0.0, 0.0))
     }
 
     /// Creates a Vector from a set of floats
-    /// 
+    ///
     /// ## Arguments
     /// - `x` X Coordinate of the Vector
     /// - `y` Y Coordinate of the Vector
     /// - `z` Z Coordinate of the Vector
     pub const fn from(x: f64, y: f64, z: f64) -> Self {
-        Self {
-            x,
-            y,
-            z
-        }
+        Self(lanes::Lanes::new(x, y, z))
+    }
+
+    /// Returns the component-wise minimum of two vectors
+    pub fn min(self, other: Self) -> Self {
+        Self::from(self.x().min(other.x()), self.y().min(other.y()), self.z().min(other.z()))
     }
-    
+
+    /// Returns the component-wise maximum of two vectors
+    pub fn max(self, other: Self) -> Self {
+        Self::from(self.x().max(other.x()), self.y().max(other.y()), self.z().max(other.z()))
+    }
+
+    /// Returns a vector with the absolute value of each component
+    pub fn abs(self) -> Self {
+        Self::from(self.x().abs(), self.y().abs(), self.z().abs())
+    }
+
+    /// Clamps each component of the vector between the matching components of `min` and `max`
+    ///
+    /// ## Arguments
+    /// - `min` Lower bound for each component
+    /// - `max` Upper bound for each component
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    /// Returns the smallest of the vector's three components
+    pub fn min_component(self) -> f64 {
+        self.x().min(self.y()).min(self.z())
+    }
+
+    /// Returns the largest of the vector's three components
+    pub fn max_component(self) -> f64 {
+        self.x().max(self.y()).max(self.z())
+    }
+
+    /// Returns a vector with each component rounded down to the nearest integer
+    pub fn floor(self) -> Self {
+        Self::from(self.x().floor(), self.y().floor(), self.z().floor())
+    }
+
+    /// Returns a vector with each component rounded up to the nearest integer
+    pub fn ceil(self) -> Self {
+        Self::from(self.x().ceil(), self.y().ceil(), self.z().ceil())
+    }
+
+    /// The vector's x component
+    pub fn x(&self) -> f64 { self.0.x() }
+
+    /// The vector's y component
+    pub fn y(&self) -> f64 { self.0.y() }
+
+    /// The vector's z component
+    pub fn z(&self) -> f64 { self.0.z() }
+
     /// Returns a vector with random values between 0 and 1
     pub fn random() -> Self {
-        Self {
-            x: random_f64(),
-            y: random_f64(),
-            z: random_f64()
-        }
+        Self::from(random_f64(), random_f64(), random_f64())
     }
-    
+
     /// Returns a random vector within the given range
     ///
     /// ## Arguments
     /// - `range` Range of numbers to generate within
     pub fn random_in_range(range: Range<f64>) -> Self {
-        Self {
-            x: random_f64_in_range(range.clone()),
-            y: random_f64_in_range(range.clone()),
-            z: random_f64_in_range(range)
-        }
+        Self::from(
+            random_f64_in_range(range.clone()),
+            random_f64_in_range(range.clone()),
+            random_f64_in_range(range)
+        )
     }
 
     /// Returns the length of the Vector
     pub fn length(&self) -> f64 {
         return self.length_squared().sqrt()
     }
-    
+
     /// Returns the length of the vector squared
     pub fn length_squared(&self) -> f64 {
-        return self.x * self.x + self.y * self.y + self.z * self.z;
+        return self.0.dot(self.0);
     }
-    
+
     /// Returns the vector's unit
     pub fn unit(&self) -> Self {
         return *self / self.length();
     }
-    
+
     /// Checks if the vector is near zero
     pub fn near_zero(&self) -> bool {
         let s = 1e-8;
-        return (self.x.abs() < s) && (self.y.abs() < s) && (self.z.abs() < s);
+        return (self.x().abs() < s) && (self.y().abs() < s) && (self.z().abs() < s);
     }
 }
 
 /// Finds the dot product of two Vectors
-/// 
+///
 /// ## Arguments
 /// - `a` The First Vector of the product
 /// - `b` The Second Vector of the product
 pub fn dot_product(a: Vector3, b: Vector3) -> f64 {
-    return a.x * b.x + a.y * b.y + a.z * b.z 
+    return a.0.dot(b.0)
 }
 
 /// Finds the cross product of two Vectors
-/// 
+///
 /// ## Arguments
 /// - `a` The First Vector of the product
 /// - `b` The Second Vector of the product
 pub fn cross_product(a: Vector3, b: Vector3) -> Vector3 {
-    return Vector3 { x: a.y * b.z - a.z * b.y, y: a.z * b.x - a.x * b.z , z: a.x * b.y - a.y * b.x }
+    return Vector3(a.0.cross(b.0))
 }
 
-/// Returns a random vector within a unit sphere
-pub fn random_in_unit_sphere() -> Vector3 {
-        loop {
-            let p = Vector3::random_in_range(-1.0..1.0);
+/// Returns a uniformly-distributed random point on the unit sphere's surface.
+///
+/// Drawn analytically rather than by rejection sampling: `z` is uniform in
+/// `[-1, 1]` and `phi` uniform in `[0, 2*PI)`, so `(sqrt(1 - z*z)*cos(phi),
+/// sqrt(1 - z*z)*sin(phi), z)` is exactly uniform on the sphere with no
+/// draws discarded.
+pub fn random_unit_vector() -> Vector3 {
+    let z = random_f64_in_range(-1.0..1.0);
+    let phi = random_f64_in_range(0.0..2.0 * PI);
+    let r = (1.0 - z * z).sqrt();
 
-            if p.length_squared() < 1.0 {
-                return p;
-            }
-        }
+    return Vector3::from(r * phi.cos(), r * phi.sin(), z);
 }
 
-/// Returns a random unit sphere's unit
-pub fn random_unit_vector() -> Vector3 {
-    return random_in_unit_sphere().unit();
+/// Returns a uniformly-distributed random point within the unit sphere's volume
+///
+/// Scales a uniform point on the sphere's surface by `random_f64().cbrt()`;
+/// the cube root (rather than a linear scale) is what keeps the result
+/// uniform over the volume instead of clustering points near the center.
+pub fn random_in_unit_sphere() -> Vector3 {
+    return random_unit_vector() * random_f64().cbrt();
 }
 
 /// Finds a random point in a hemisphere
@@ -248,6 +637,28 @@ pub fn random_on_hemisphere(normal: Vector3) -> Vector3 {
     }
 }
 
+/// Returns a tangent-space direction distributed proportional to
+/// `cos(theta)` about the `+Z` axis.
+///
+/// `r1`/`r2` are drawn uniformly in `[0, 1)`; `phi = 2*PI*r1` picks the
+/// azimuth and `z = sqrt(1 - r2)` biases the polar angle toward the pole so
+/// directions cluster near the normal rather than spreading uniformly over
+/// the hemisphere. Pass the result through an [`OrthonormalBasis`] built
+/// around the surface normal to get a world-space scatter direction.
+///
+/// The matching PDF is `cos(theta) / PI`, which cancels the cosine term in
+/// the rendering equation and converges faster than uniform hemisphere
+/// sampling for Lambertian surfaces.
+pub fn random_cosine_direction() -> Vector3 {
+    let r1 = random_f64();
+    let r2 = random_f64();
+    let phi = 2.0 * PI * r1;
+    let z = (1.0 - r2).sqrt();
+    let r = r2.sqrt();
+
+    return Vector3::from(phi.cos() * r, phi.sin() * r, z);
+}
+
 /// Reflects a vector and a normal
 pub fn reflect(v: Vector3, n: Vector3) -> Vector3 {
     return v - 2.0 * dot_product(v, n) * n;
@@ -263,11 +674,122 @@ pub fn refract(uv: Vector3, n: Vector3, etai_over_etat: f64) -> Vector3 {
     return r_out_perp + r_out_parallel;
 }
 
+/// Returns a uniformly-distributed random point within the unit disk
+///
+/// Drawn analytically: `theta` is uniform in `[0, 2*PI)` and `radius` is
+/// `sqrt(random_f64())` rather than `random_f64()` directly, since the sqrt
+/// is what keeps points area-uniform instead of clustering near the center.
 pub fn random_in_unit_disk() -> Vector3 {
-    loop {
-        let p = Vector3::from(random_f64_in_range(-1.0..1.0), random_f64_in_range(-1.0..1.0), 0.0);
-        if p.length_squared() < 1.0 {
-            return p;
+    let theta = random_f64_in_range(0.0..2.0 * PI);
+    let radius = random_f64().sqrt();
+
+    return Vector3::from(radius * theta.cos(), radius * theta.sin(), 0.0);
+}
+
+/// Builds a right-handed orthonormal frame `(tangent, bitangent, n)` from a
+/// single normalized vector `n`.
+///
+/// Follows pbrt's numerically-stable construction rather than the naive
+/// "cross with an arbitrary axis" approach, which suffers catastrophic
+/// cancellation as `n` approaches the poles.
+///
+/// ## Arguments
+/// - `n` The (already normalized) vector to build a frame around
+pub fn coordinate_system(n: Vector3) -> (Vector3, Vector3, Vector3) {
+    let sign = if n.z() < 0.0 { -1.0 } else { 1.0 };
+    let a = -1.0 / (sign + n.z());
+    let b = n.x() * n.y() * a;
+
+    let tangent = Vector3::from(1.0 + sign * n.x() * n.x() * a, sign * b, -sign * n.x());
+    let bitangent = Vector3::from(b, sign + n.y() * n.y() * a, -n.y());
+
+    return (tangent, bitangent, n);
+}
+
+/// A right-handed orthonormal frame built around a single normal vector,
+/// used to map tangent-space directions (e.g. sampled hemisphere
+/// directions) into world space.
+pub struct OrthonormalBasis {
+    u: Vector3,
+    v: Vector3,
+    w: Vector3
+}
+
+impl OrthonormalBasis {
+    /// Builds the frame's `u`/`v` tangent vectors around `n` using
+    /// [`coordinate_system`]
+    ///
+    /// ## Arguments
+    /// - `n` The (already normalized) vector to build the frame around
+    pub fn new(n: Vector3) -> Self {
+        let (u, v, w) = coordinate_system(n);
+        Self { u, v, w }
+    }
+
+    /// Maps a tangent-space direction `v` into world space
+    pub fn local_to_world(&self, v: Vector3) -> Vector3 {
+        return v.x() * self.u + v.y() * self.v + v.z() * self.w;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn accessor_methods_match_deref_fields() {
+        let v = Vector3::from(1.0, 2.0, 3.0);
+        assert_eq!(v.x(), v.x);
+        assert_eq!(v.y(), v.y);
+        assert_eq!(v.z(), v.z);
+    }
+
+    #[test]
+    fn index_matches_accessor_methods() {
+        let v = Vector3::from(4.0, 5.0, 6.0);
+        assert_eq!(v[0], v.x());
+        assert_eq!(v[1], v.y());
+        assert_eq!(v[2], v.z());
+    }
+
+    #[test]
+    fn coordinate_system_is_orthonormal() {
+        let normals = [
+            Vector3::Z,
+            Vector3::NEG_Z,
+            Vector3::X,
+            Vector3::from(0.6, 0.8, 0.0),
+            Vector3::from(1.0, 1.0, 1.0).unit(),
+            Vector3::from(-0.3, 0.5, -0.8).unit(),
+        ];
+
+        for n in normals {
+            let (tangent, bitangent, w) = coordinate_system(n);
+
+            assert!((tangent.length() - 1.0).abs() < EPSILON);
+            assert!((bitangent.length() - 1.0).abs() < EPSILON);
+            assert!((w.length() - 1.0).abs() < EPSILON);
+
+            assert!(dot_product(tangent, bitangent).abs() < EPSILON);
+            assert!(dot_product(tangent, w).abs() < EPSILON);
+            assert!(dot_product(bitangent, w).abs() < EPSILON);
+
+            // Right-handed: tangent x bitangent should equal n.
+            let cross = cross_product(tangent, bitangent);
+            assert!((cross.x() - w.x()).abs() < EPSILON);
+            assert!((cross.y() - w.y()).abs() < EPSILON);
+            assert!((cross.z() - w.z()).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn random_cosine_direction_is_unit_length_in_upper_hemisphere() {
+        for _ in 0..100 {
+            let d = random_cosine_direction();
+            assert!((d.length() - 1.0).abs() < EPSILON);
+            assert!(d.z() >= 0.0);
         }
     }
 }